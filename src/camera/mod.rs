@@ -0,0 +1,4 @@
+//! Cameras turning pixels into world-space rays
+mod perspective;
+
+pub use perspective::PerspectiveCamera;
@@ -0,0 +1,69 @@
+//! Pinhole perspective camera
+//!
+//! Rays are generated by pushing a raster pixel back through the
+//! raster→screen→camera chain and then out into the world, reusing the
+//! `Transform` machinery rather than hand-rolling any projection maths.
+//!
+//! # Examples
+//!
+//! ```
+//! use pathtrace::core::{Vector3f, Transform};
+//! use pathtrace::camera::PerspectiveCamera;
+//!
+//! let c2w = Transform::new_look_at(
+//!     &Vector3f::new(0.0),
+//!     &Vector3f::new_xyz(0.0, 0.0, 1.0),
+//!     &Vector3f::new_xyz(0.0, 1.0, 0.0));
+//! let cam = PerspectiveCamera::new(c2w, 90.0, 640.0, 480.0);
+//! let (origin, _dir) = cam.generate_ray(320.0, 240.0);
+//!
+//! assert!(origin.len() < 1e-5);
+//! ```
+use crate::Float;
+use crate::core::{Transform, Vector3f};
+
+pub struct PerspectiveCamera {
+    camera_to_world: Transform,
+    raster_to_camera: Transform,
+}
+
+impl PerspectiveCamera {
+    /// Builds a camera looking through `camera_to_world`
+    ///
+    /// `fov` is the field of view in degrees across the shorter screen axis
+    /// and `xres`/`yres` the film resolution in pixels.
+    pub fn new(camera_to_world: Transform, fov: Float, xres: Float, yres: Float) -> Self {
+        let aspect = xres / yres;
+        let (xmin, xmax, ymin, ymax) = if aspect > 1.0 {
+            (-aspect, aspect, -1.0, 1.0)
+        } else {
+            (-1.0, 1.0, -1.0 / aspect, 1.0 / aspect)
+        };
+
+        let screen_to_raster =
+            Transform::new_scale(xres, yres, 1.0)
+            * Transform::new_scale(1.0 / (xmax - xmin), 1.0 / (ymin - ymax), 1.0)
+            * Transform::new_translate(-xmin, -ymax, 0.0);
+
+        let camera_to_screen = Transform::new_perspective(fov, 1e-2, 1000.0);
+
+        let raster_to_camera = camera_to_screen.inverse() * screen_to_raster.inverse();
+
+        PerspectiveCamera {
+            camera_to_world,
+            raster_to_camera,
+        }
+    }
+
+    /// Generates a world-space ray through the raster pixel `(px, py)`
+    ///
+    /// Returns the ray origin and a normalized direction.
+    pub fn generate_ray(&self, px: Float, py: Float) -> (Vector3f, Vector3f) {
+        let p_camera = self.raster_to_camera.eval_point(&Vector3f::new_xyz(px, py, 0.0));
+
+        let origin = self.camera_to_world.eval_point(&Vector3f::new(0.0));
+        let dir = self.camera_to_world.eval_vector(&p_camera.norm()).norm();
+
+        (origin, dir)
+    }
+}
@@ -0,0 +1,107 @@
+//! Axis-aligned bounding boxes
+//!
+//! Used to bound shapes and, later, as the building block of acceleration
+//! structures. A `Bound3` is stored as its minimum and maximum corner.
+use crate::{Float, Number};
+use crate::core::{Vector3, Vector3f};
+
+#[derive(Clone, Copy)]
+pub struct Bound3<T: Number> {
+    pub min: Vector3<T>,
+    pub max: Vector3<T>,
+}
+
+pub type Bound3f = Bound3<Float>;
+
+fn min<T: Number>(a: T, b: T) -> T {
+    if a < b { a } else { b }
+}
+
+fn max<T: Number>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+impl<T: Number> Bound3<T> {
+    pub fn new(min: Vector3<T>, max: Vector3<T>) -> Bound3<T> {
+        Bound3 { min, max }
+    }
+
+    /// The smallest box enclosing both `self` and `op`
+    pub fn union(&self, op: &Self) -> Self {
+        Bound3 {
+            min: Vector3::new_xyz(
+                min(self.min.x, op.min.x),
+                min(self.min.y, op.min.y),
+                min(self.min.z, op.min.z),
+            ),
+            max: Vector3::new_xyz(
+                max(self.max.x, op.max.x),
+                max(self.max.y, op.max.y),
+                max(self.max.z, op.max.z),
+            ),
+        }
+    }
+
+    /// The smallest box enclosing `self` and the point `p`
+    pub fn union_point(&self, p: &Vector3<T>) -> Self {
+        Bound3 {
+            min: Vector3::new_xyz(
+                min(self.min.x, p.x),
+                min(self.min.y, p.y),
+                min(self.min.z, p.z),
+            ),
+            max: Vector3::new_xyz(
+                max(self.max.x, p.x),
+                max(self.max.y, p.y),
+                max(self.max.z, p.z),
+            ),
+        }
+    }
+
+    /// Whether the point `p` lies inside the box
+    pub fn contains(&self, p: &Vector3<T>) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x &&
+        p.y >= self.min.y && p.y <= self.max.y &&
+        p.z >= self.min.z && p.z <= self.max.z
+    }
+
+    /// The vector spanning the box from its min to its max corner
+    pub fn diagonal(&self) -> Vector3<T> {
+        self.max - self.min
+    }
+}
+
+impl Bound3f {
+    /// Intersects a ray with the box using the slab method
+    ///
+    /// `inv_dir` must be the component-wise reciprocal of the ray direction,
+    /// passed precomputed to avoid a division per box. Returns the entry and
+    /// exit parameters along the whole line through `origin`, or `None` when
+    /// that line misses the box; both parameters are negative when the box
+    /// lies behind `origin`.
+    pub fn intersect_ray(&self, origin: &Vector3f, inv_dir: &Vector3f) -> Option<(Float, Float)> {
+        let mut tmin = Float::NEG_INFINITY;
+        let mut tmax = Float::INFINITY;
+
+        let lo = [self.min.x, self.min.y, self.min.z];
+        let hi = [self.max.x, self.max.y, self.max.z];
+        let o = [origin.x, origin.y, origin.z];
+        let inv = [inv_dir.x, inv_dir.y, inv_dir.z];
+
+        for axis in 0..3 {
+            let mut t0 = (lo[axis] - o[axis]) * inv[axis];
+            let mut t1 = (hi[axis] - o[axis]) * inv[axis];
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            tmin = tmin.max(t0);
+            tmax = tmax.min(t1);
+        }
+
+        if tmin > tmax {
+            None
+        } else {
+            Some((tmin, tmax))
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod vector3;
+mod matrix4x4;
+mod transform;
+mod bound3;
+#[cfg(feature = "simd")]
+mod simd;
+
+pub use vector3::{Vector3, Vector3f};
+pub use matrix4x4::Matrix4x4f;
+pub use transform::Transform;
+pub use bound3::{Bound3, Bound3f};
@@ -0,0 +1,68 @@
+//! Small 4-wide float vector used by the `simd` backend
+//!
+//! Only the handful of operations needed by the column-major transform
+//! multiplies are provided. On `x86_64` this maps to SSE registers; every
+//! other target falls back to a scalar array that the compiler is free to
+//! autovectorize.
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[derive(Clone, Copy)]
+pub struct F32x4(
+    #[cfg(target_arch = "x86_64")] __m128,
+    #[cfg(not(target_arch = "x86_64"))] [f32; 4],
+);
+
+#[cfg(target_arch = "x86_64")]
+impl F32x4 {
+    #[inline]
+    pub fn new(a: f32, b: f32, c: f32, d: f32) -> F32x4 {
+        unsafe { F32x4(_mm_set_ps(d, c, b, a)) }
+    }
+
+    #[inline]
+    pub fn splat(v: f32) -> F32x4 {
+        unsafe { F32x4(_mm_set1_ps(v)) }
+    }
+
+    /// Multiply-add: `self * m + acc`, as a separate multiply and add (not fused)
+    #[inline]
+    pub fn mul_add(self, m: F32x4, acc: F32x4) -> F32x4 {
+        unsafe { F32x4(_mm_add_ps(_mm_mul_ps(self.0, m.0), acc.0)) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [f32; 4] {
+        let mut out = [0.0f32; 4];
+        unsafe { _mm_storeu_ps(out.as_mut_ptr(), self.0) };
+        out
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+impl F32x4 {
+    #[inline]
+    pub fn new(a: f32, b: f32, c: f32, d: f32) -> F32x4 {
+        F32x4([a, b, c, d])
+    }
+
+    #[inline]
+    pub fn splat(v: f32) -> F32x4 {
+        F32x4([v; 4])
+    }
+
+    #[inline]
+    pub fn mul_add(self, m: F32x4, acc: F32x4) -> F32x4 {
+        F32x4([
+            self.0[0] * m.0[0] + acc.0[0],
+            self.0[1] * m.0[1] + acc.0[1],
+            self.0[2] * m.0[2] + acc.0[2],
+            self.0[3] * m.0[3] + acc.0[3],
+        ])
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [f32; 4] {
+        self.0
+    }
+}
@@ -21,16 +21,25 @@ use std::ops;
 
 pub struct Transform {
     m: Matrix4x4f,
+    minv: Matrix4x4f,
 }
 
 impl Transform {
     pub fn new() -> Transform {
-        Transform {
-            m: Matrix4x4f::new_ident(1.0),
-        }
+        Transform::from_matrix(Matrix4x4f::new_ident(1.0))
+    }
+
+    /// Builds a transform from a matrix, caching its inverse
+    ///
+    /// The inverse is computed once here so `inverse` and `eval_normal` can
+    /// reuse it instead of inverting on every call.
+    fn from_matrix(m: Matrix4x4f) -> Transform {
+        let minv = m.inverse();
+        Transform { m, minv }
     }
 
     /// Evaluation a point through the matrix
+    #[cfg(not(feature = "simd"))]
     pub fn eval_point(&self, p: &Vector3f) -> Vector3f {
         let m = &self.m.m;
         let x = m[0][0]*p.x + m[0][1]*p.y + m[0][2]*p.z + m[0][3];
@@ -46,9 +55,36 @@ impl Transform {
         out
     }
 
+    /// Evaluation a point through the matrix
+    ///
+    /// Column-major SIMD variant: each matrix column is broadcast-multiplied
+    /// by one point component and accumulated, matching the scalar result.
+    #[cfg(feature = "simd")]
+    pub fn eval_point(&self, p: &Vector3f) -> Vector3f {
+        use super::simd::F32x4;
+        let m = &self.m.m;
+        let c0 = F32x4::new(m[0][0], m[1][0], m[2][0], m[3][0]);
+        let c1 = F32x4::new(m[0][1], m[1][1], m[2][1], m[3][1]);
+        let c2 = F32x4::new(m[0][2], m[1][2], m[2][2], m[3][2]);
+        let c3 = F32x4::new(m[0][3], m[1][3], m[2][3], m[3][3]);
+
+        let r = c0.mul_add(F32x4::splat(p.x), c3);
+        let r = c1.mul_add(F32x4::splat(p.y), r);
+        let r = c2.mul_add(F32x4::splat(p.z), r);
+        let [x, y, z, w] = r.to_array();
+
+        let mut out = Vector3f::new_xyz(x, y, z);
+        if w != 1.0 {
+            out /= w;
+        }
+
+        out
+    }
+
     /// Evaluation of a vector
     ///
     /// This will not work for normal vectors as they become distorted
+    #[cfg(not(feature = "simd"))]
     pub fn eval_vector(&self, v: &Vector3f) -> Vector3f {
         let m = &self.m.m;
         let x = m[0][0]*v.x + m[0][1]*v.y + m[0][2]*v.z;
@@ -58,9 +94,45 @@ impl Transform {
         Vector3f::new_xyz(x, y, z)
     }
 
+    /// Evaluation of a vector
+    ///
+    /// This will not work for normal vectors as they become distorted
+    #[cfg(feature = "simd")]
+    pub fn eval_vector(&self, v: &Vector3f) -> Vector3f {
+        use super::simd::F32x4;
+        let m = &self.m.m;
+        let c0 = F32x4::new(m[0][0], m[1][0], m[2][0], 0.0);
+        let c1 = F32x4::new(m[0][1], m[1][1], m[2][1], 0.0);
+        let c2 = F32x4::new(m[0][2], m[1][2], m[2][2], 0.0);
+
+        let r = c0.mul_add(F32x4::splat(v.x), F32x4::splat(0.0));
+        let r = c1.mul_add(F32x4::splat(v.y), r);
+        let r = c2.mul_add(F32x4::splat(v.z), r);
+        let [x, y, z, _] = r.to_array();
+
+        Vector3f::new_xyz(x, y, z)
+    }
+
+    /// Evaluation of a normal vector
+    ///
+    /// Normals must be multiplied by the transpose of the inverse to stay
+    /// perpendicular to their surface after non-uniform scaling. The cached
+    /// inverse is indexed transposed and the result renormalized.
+    pub fn eval_normal(&self, n: &Vector3f) -> Vector3f {
+        let minv = &self.minv.m;
+        let x = minv[0][0]*n.x + minv[1][0]*n.y + minv[2][0]*n.z;
+        let y = minv[0][1]*n.x + minv[1][1]*n.y + minv[2][1]*n.z;
+        let z = minv[0][2]*n.x + minv[1][2]*n.y + minv[2][2]*n.z;
+
+        let mut out = Vector3f::new_xyz(x, y, z);
+        out.norm_in();
+        out
+    }
+
     pub fn inverse(&self) -> Self {
         Transform {
-            m: self.m.inverse(),
+            m: self.minv,
+            minv: self.m,
         }
     }
 }
@@ -69,66 +141,92 @@ impl ops::Mul for Transform {
     type Output = Transform;
 
     fn mul(self, op: Self) -> Self::Output {
-        Transform {
-            m: &self.m * &op.m
-        }
+        Transform::from_matrix(&self.m * &op.m)
     }
 }
 
 // Creation of different transformations
 impl Transform {
     pub fn new_translate(x: Float, y: Float, z: Float) -> Self {
-        Transform { m: Matrix4x4f::new(
+        Transform::from_matrix(Matrix4x4f::new(
                 1.0, 0.0, 0.0, x,
                 0.0, 1.0, 0.0, y,
                 0.0, 0.0, 1.0, z,
-                0.0, 0.0, 0.0, 1.0)
-        }
+                0.0, 0.0, 0.0, 1.0))
     }
 
     pub fn new_scale(x: Float, y: Float, z: Float) -> Self {
-        Transform { m: Matrix4x4f::new(
+        Transform::from_matrix(Matrix4x4f::new(
                 x, 0.0, 0.0, 0.0,
                 0.0, y, 0.0, 0.0,
                 0.0, 0.0, z, 0.0,
-                0.0, 0.0, 0.0, 1.0)
-        }
+                0.0, 0.0, 0.0, 1.0))
     }
 
     pub fn new_rotate_x(theta: Float) -> Self {
         let theta = theta.to_radians();
         let cost = theta.cos();
         let sint = theta.sin();
-        Transform { m: Matrix4x4f::new(
+        Transform::from_matrix(Matrix4x4f::new(
                 1.0, 0.0, 0.0, 0.0,
                 0.0, cost, -sint, 0.0,
                 0.0, sint, cost, 0.0,
-                0.0, 0.0, 0.0, 1.0)
-        }
+                0.0, 0.0, 0.0, 1.0))
     }
 
     pub fn new_rotate_y(theta: Float) -> Self {
         let theta = theta.to_radians();
         let cost = theta.cos();
         let sint = theta.sin();
-        Transform { m: Matrix4x4f::new(
+        Transform::from_matrix(Matrix4x4f::new(
                 cost, 0.0, sint, 0.0,
                 0.0, 1.0, 0.0, 0.0,
                 -sint, 0.0, cost, 0.0,
-                0.0, 0.0, 0.0, 1.0)
-        }
+                0.0, 0.0, 0.0, 1.0))
     }
 
     pub fn new_rotate_z(theta: Float) -> Self {
         let theta = theta.to_radians();
         let cost = theta.cos();
         let sint = theta.sin();
-        Transform { m: Matrix4x4f::new(
+        Transform::from_matrix(Matrix4x4f::new(
                 cost, -sint, 0.0, 0.0,
                 sint, cost, 0.0, 0.0,
                 0.0, 0.0, 1.1, 0.0,
-                0.0, 0.0, 0.0, 1.0)
-        }
+                0.0, 0.0, 0.0, 1.0))
+    }
+
+    /// Rotation about an arbitrary axis using Rodrigues' formula
+    ///
+    /// `axis` is normalized internally, so callers need not pre-normalize it.
+    /// `theta` is given in degrees like the other rotation constructors.
+    pub fn new_rotate(axis: &Vector3f, theta: Float) -> Self {
+        let a = axis.norm();
+        let (x, y, z) = (a.x, a.y, a.z);
+        let theta = theta.to_radians();
+        let c = theta.cos();
+        let s = theta.sin();
+        Transform::from_matrix(Matrix4x4f::new(
+                c + x*x*(1.0-c),     x*y*(1.0-c) - z*s,   x*z*(1.0-c) + y*s,   0.0,
+                y*x*(1.0-c) + z*s,   c + y*y*(1.0-c),     y*z*(1.0-c) - x*s,   0.0,
+                z*x*(1.0-c) - y*s,   z*y*(1.0-c) + x*s,   c + z*z*(1.0-c),     0.0,
+                0.0,                 0.0,                 0.0,                 1.0))
+    }
+
+    /// Perspective projection mapping camera space onto the screen window
+    ///
+    /// `fov` is the field of view in degrees; `near`/`far` bound the viewing
+    /// volume along z. `x` and `y` are divided by `z` (a projection onto the
+    /// `z == 1` plane), `z` is remapped so `near` goes to 0 and `far` to 1, and
+    /// the field of view is scaled so the screen window spans `[-1, 1]`.
+    pub fn new_perspective(fov: Float, near: Float, far: Float) -> Self {
+        let persp = Matrix4x4f::new(
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, far/(far-near), -far*near/(far-near),
+                0.0, 0.0, 1.0, 0.0);
+        let inv_tan = 1.0 / (fov.to_radians() / 2.0).tan();
+        Transform::new_scale(inv_tan, inv_tan, 1.0) * Transform::from_matrix(persp)
     }
 
     pub fn new_look_at(pos: &Vector3f, look: &Vector3f, up: &Vector3f) -> Self {
@@ -136,11 +234,10 @@ impl Transform {
         let right = up.norm().cross(&dir).norm();
         let newup = dir.cross(&right);
 
-        Transform { m: Matrix4x4f::new(
+        Transform::from_matrix(Matrix4x4f::new(
                 right.x, newup.x, dir.x, pos.x,
                 right.y, newup.y, dir.y, pos.y,
                 right.z, newup.z, dir.z, pos.z,
-                0.0    , 0.0    , 0.0  , 1.0)
-        }
+                0.0    , 0.0    , 0.0  , 1.0))
     }
 }
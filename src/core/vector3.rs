@@ -1,5 +1,5 @@
 use crate::{Float, Number};
-use std::ops::{Sub, Add, DivAssign};
+use std::ops::{Sub, Add, Mul, Neg, DivAssign};
 
 #[derive(Clone, Copy)]
 pub struct Vector3<T: Number> {
@@ -46,6 +46,24 @@ impl<T: Number> Add for Vector3<T> {
     }
 }
 
+impl<T: Number> Mul<T> for Vector3<T> {
+    type Output = Self;
+    fn mul(self, op: T) -> Self::Output {
+        Self::new_xyz(
+            self.x * op,
+            self.y * op,
+            self.z * op,
+        )
+    }
+}
+
+impl<T: Number + Neg<Output = T>> Neg for Vector3<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new_xyz(-self.x, -self.y, -self.z)
+    }
+}
+
 impl<T: Number> DivAssign<T> for Vector3<T> {
     fn div_assign(&mut self, op: T) {
         self.x /= op;
@@ -90,4 +108,22 @@ impl Vector3f {
             )
 
     }
+
+    /// Reflects the vector about a surface normal
+    pub fn reflect(&self, n: &Vector3f) -> Self {
+        *self - *n * (2.0 * self.dot(n))
+    }
+
+    /// Refracts the vector through a surface with relative index `eta`
+    ///
+    /// Returns `None` on total internal reflection.
+    pub fn refract(&self, n: &Vector3f, eta: Float) -> Option<Self> {
+        let cos_i = -self.dot(n);
+        let k = 1.0 - eta*eta*(1.0 - cos_i*cos_i);
+        if k < 0.0 {
+            None
+        } else {
+            Some(*self * eta + *n * (eta*cos_i - k.sqrt()))
+        }
+    }
 }
\ No newline at end of file